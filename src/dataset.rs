@@ -0,0 +1,184 @@
+use crate::Error;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
+
+/// A labeled set of samples for batch evaluation or training: each entry pairs a feature
+/// `HashMap` (as consumed by [`crate::ModelData::norm_predict`]) with its target fee rate.
+pub struct Dataset(pub Vec<(HashMap<String, f32>, f32)>);
+
+impl Dataset {
+    /// Parses the libsvm sparse format (`label index:value index:value ...`, 1-based indices)
+    /// used by liblinear, mapping each index to a field name via `field_order`.
+    pub fn from_libsvm<R: Read>(reader: R, field_order: &[String]) -> Result<Self, Error> {
+        let mut samples = vec![];
+
+        for line in BufReader::new(reader).lines() {
+            let line = line.map_err(Error::Io)?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            let target: f32 = tokens
+                .next()
+                .ok_or_else(|| Error::InvalidDataset(line.to_string()))?
+                .parse()
+                .map_err(|_| Error::InvalidDataset(line.to_string()))?;
+
+            let mut features = HashMap::new();
+            for token in tokens {
+                let (index, value) = token
+                    .split_once(':')
+                    .ok_or_else(|| Error::InvalidDataset(line.to_string()))?;
+                let index: usize = index
+                    .parse()
+                    .map_err(|_| Error::InvalidDataset(line.to_string()))?;
+                let value: f32 = value
+                    .parse()
+                    .map_err(|_| Error::InvalidDataset(line.to_string()))?;
+                let field = index
+                    .checked_sub(1)
+                    .and_then(|zero_based| field_order.get(zero_based))
+                    .ok_or_else(|| Error::InvalidDataset(line.to_string()))?;
+                features.insert(field.clone(), value);
+            }
+
+            samples.push((features, target));
+        }
+
+        Ok(Self(samples))
+    }
+
+    /// Parses a CSV file whose header row names the feature columns, with the target fee rate
+    /// as the last column.
+    pub fn from_csv<R: Read>(reader: R) -> Result<Self, Error> {
+        let mut reader = csv::Reader::from_reader(reader);
+        let headers: Vec<String> = reader
+            .headers()
+            .map_err(Error::Csv)?
+            .iter()
+            .map(str::to_string)
+            .collect();
+        let (_target_name, field_names) = headers
+            .split_last()
+            .ok_or_else(|| Error::InvalidDataset("empty csv header".to_string()))?;
+
+        let mut samples = vec![];
+        for record in reader.records() {
+            let record = record.map_err(Error::Csv)?;
+
+            let mut features = HashMap::new();
+            for (field, value) in field_names.iter().zip(record.iter()) {
+                let value: f32 = value
+                    .parse()
+                    .map_err(|_| Error::InvalidDataset(record.as_slice().to_string()))?;
+                features.insert(field.clone(), value);
+            }
+
+            let target: f32 = record
+                .get(record.len() - 1)
+                .ok_or_else(|| Error::InvalidDataset(record.as_slice().to_string()))?
+                .parse()
+                .map_err(|_| Error::InvalidDataset(record.as_slice().to_string()))?;
+
+            samples.push((features, target));
+        }
+
+        Ok(Self(samples))
+    }
+}
+
+/// Prediction error of a model over a [`Dataset`], in the units of the target (fee rate).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Metrics {
+    pub mae: f32,
+    pub rmse: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Matrix;
+    use crate::model_data::FieldsDescribe;
+    use crate::weights::Weights;
+    use crate::ModelData;
+    use std::io::Cursor;
+
+    fn field_order() -> Vec<String> {
+        vec!["a".to_string(), "b".to_string()]
+    }
+
+    #[test]
+    fn test_from_libsvm_round_trips_fields() {
+        let dataset = Dataset::from_libsvm(Cursor::new("5.0 1:10 2:20\n"), &field_order()).unwrap();
+
+        assert_eq!(1, dataset.0.len());
+        let (features, target) = &dataset.0[0];
+        assert_eq!(5.0, *target);
+        assert_eq!(Some(&10.0), features.get("a"));
+        assert_eq!(Some(&20.0), features.get("b"));
+    }
+
+    #[test]
+    fn test_from_libsvm_rejects_zero_index() {
+        let result = Dataset::from_libsvm(Cursor::new("5.0 0:10\n"), &field_order());
+        assert!(result.is_err(), "index 0 is not a valid 1-based libsvm index");
+    }
+
+    #[test]
+    fn test_from_csv_round_trips() {
+        let dataset = Dataset::from_csv(Cursor::new("a,b,target\n1,2,3\n4,5,6\n")).unwrap();
+
+        assert_eq!(2, dataset.0.len());
+        let (features, target) = &dataset.0[0];
+        assert_eq!(3.0, *target);
+        assert_eq!(Some(&1.0), features.get("a"));
+        assert_eq!(Some(&2.0), features.get("b"));
+
+        let (features, target) = &dataset.0[1];
+        assert_eq!(6.0, *target);
+        assert_eq!(Some(&4.0), features.get("a"));
+        assert_eq!(Some(&5.0), features.get("b"));
+    }
+
+    /// A single-layer, no-op model (identity kernel, zero bias, identity normalization) so the
+    /// expected MAE/RMSE over `dataset` can be hand-computed from the raw `x`/`target` values.
+    fn identity_model() -> ModelData {
+        let mut mean = HashMap::new();
+        mean.insert("x".to_string(), 0.0);
+        let mut std = HashMap::new();
+        std.insert("x".to_string(), 1.0);
+
+        ModelData {
+            norm: FieldsDescribe::new(mean, std),
+            weights: Weights::from_layers(vec![(Matrix::from_rows(vec![vec![1.0]]), vec![0.0])]),
+            fields: vec!["x".to_string()],
+            alpha: 0.01,
+        }
+    }
+
+    fn sample(x: f32, target: f32) -> (HashMap<String, f32>, f32) {
+        let mut features = HashMap::new();
+        features.insert("x".to_string(), x);
+        (features, target)
+    }
+
+    #[test]
+    fn test_evaluate_matches_hand_computed_metrics() {
+        let model = identity_model();
+        // predict(x) == x, so errors are exactly x - target: -1, 1, 0.
+        let dataset = Dataset(vec![sample(1.0, 2.0), sample(5.0, 4.0), sample(10.0, 10.0)]);
+
+        let metrics = model.evaluate(&dataset).unwrap();
+
+        assert!((metrics.mae - (2.0 / 3.0)).abs() < 1e-4);
+        assert!((metrics.rmse - (2.0f32 / 3.0).sqrt()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_evaluate_rejects_empty_dataset() {
+        let model = identity_model();
+        assert!(model.evaluate(&Dataset(vec![])).is_err());
+    }
+}
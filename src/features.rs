@@ -0,0 +1,109 @@
+//! Gated behind the `bitcoin` feature: this is the only module that depends on the
+//! `bitcoin` crate, and consumers who don't need live mempool/block feature extraction
+//! shouldn't have to pull it in.
+#![cfg(feature = "bitcoin")]
+
+use bitcoin::Transaction;
+use std::collections::HashMap;
+
+/// Upper edge (in sat/vB) of each of the 16 fee-rate histogram buckets (`b0..b15`). A
+/// transaction falls into the first bucket whose edge it doesn't exceed; anything above the
+/// last edge lands in `b15`.
+const BUCKET_EDGES: [f32; 15] = [
+    1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 8.0, 10.0, 12.0, 15.0, 20.0, 30.0, 40.0, 50.0, 60.0,
+];
+
+/// Builds the `HashMap<String, f32>` expected by [`crate::ModelData::norm_predict`] directly
+/// from a set of mempool transactions, so callers don't have to bucket fee rates or derive
+/// time features by hand. The map holds `b0..b15`, `delta_last`, `day_of_week` and `hour`;
+/// it does not include `confirms_in`.
+pub struct FeatureExtractor;
+
+impl FeatureExtractor {
+    /// `mempool` pairs every candidate transaction with its fee rate (sat/vB). `now` and
+    /// `last_block_time` are Unix timestamps (seconds): `now` is the current time and
+    /// `last_block_time` that of the most recently confirmed block, used to compute
+    /// `delta_last`. Taking `now` as a parameter (rather than reading the clock internally)
+    /// keeps this deterministic and testable.
+    pub fn from_mempool(
+        mempool: &[(Transaction, f32)],
+        now: u64,
+        last_block_time: u64,
+    ) -> HashMap<String, f32> {
+        let mut buckets = [0u64; 16];
+        for (_, fee_rate) in mempool {
+            buckets[bucket_index(*fee_rate)] += 1;
+        }
+
+        let (day_of_week, hour) = day_of_week_and_hour(now);
+
+        let mut features = HashMap::with_capacity(19);
+        for (i, count) in buckets.iter().enumerate() {
+            features.insert(format!("b{}", i), *count as f32);
+        }
+        features.insert(
+            "delta_last".to_string(),
+            now.saturating_sub(last_block_time) as f32,
+        );
+        features.insert("day_of_week".to_string(), day_of_week as f32);
+        features.insert("hour".to_string(), hour as f32);
+
+        features
+    }
+}
+
+fn bucket_index(fee_rate: f32) -> usize {
+    BUCKET_EDGES
+        .iter()
+        .position(|&edge| fee_rate <= edge)
+        .unwrap_or(BUCKET_EDGES.len())
+}
+
+/// `ts` is a Unix timestamp. 1970-01-01 was a Thursday, so day 0 (Sunday) started 4 days
+/// before the epoch.
+fn day_of_week_and_hour(ts: u64) -> (u64, u64) {
+    let days_since_epoch = ts / 86_400;
+    let day_of_week = (days_since_epoch + 4) % 7;
+    let hour = (ts % 86_400) / 3_600;
+    (day_of_week, hour)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_index_edges() {
+        assert_eq!(0, bucket_index(0.0));
+        assert_eq!(0, bucket_index(1.0));
+        assert_eq!(1, bucket_index(1.5));
+        assert_eq!(1, bucket_index(2.0));
+        assert_eq!(14, bucket_index(60.0));
+        assert_eq!(15, bucket_index(60.1));
+        assert_eq!(15, bucket_index(1_000.0));
+    }
+
+    #[test]
+    fn test_day_of_week_and_hour_epoch() {
+        // 1970-01-01 00:00:00 UTC was a Thursday.
+        assert_eq!((4, 0), day_of_week_and_hour(0));
+        // One full day plus 2 hours and 1 second later: Friday, 02:00.
+        assert_eq!((5, 2), day_of_week_and_hour(86_400 + 2 * 3_600 + 1));
+        // A week later lands back on Thursday.
+        assert_eq!((4, 0), day_of_week_and_hour(7 * 86_400));
+    }
+
+    #[test]
+    fn test_from_mempool_computes_all_features() {
+        let now = 7 * 86_400 + 3 * 3_600;
+        let last_block_time = now - 956;
+
+        let features = FeatureExtractor::from_mempool(&[], now, last_block_time);
+
+        assert_eq!(Some(&0.0), features.get("b0"));
+        assert_eq!(Some(&956.0), features.get("delta_last"));
+        assert_eq!(Some(&4.0), features.get("day_of_week"));
+        assert_eq!(Some(&3.0), features.get("hour"));
+        assert_eq!(19, features.len());
+    }
+}
@@ -1,8 +1,25 @@
 use crate::matrix::Matrix;
+use crate::weights::Weights;
 use crate::Error;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::io::{BufReader, Read};
+use std::io::{BufReader, Read, Write};
+
+/// Serialization backend used to encode/decode a [`ModelData`].
+///
+/// `Cbor` is always available and remains the default used by [`ModelData::from_reader`];
+/// the other variants are opt-in via the matching cargo feature so consumers who only need
+/// CBOR don't pay for the extra dependencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelFormat {
+    Cbor,
+    #[cfg(feature = "json")]
+    Json,
+    #[cfg(feature = "msgpack")]
+    MessagePack,
+    #[cfg(feature = "bincode")]
+    Bincode,
+}
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ModelData {
@@ -12,50 +29,138 @@ pub struct ModelData {
     pub alpha: f32,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Weights {
-    #[serde(rename = "dense/bias:0")]
-    pub l0_bias: Vec<f32>,
-    #[serde(rename = "dense/kernel:0")]
-    pub l0_kernel: Matrix,
-
-    #[serde(rename = "dense_1/bias:0")]
-    pub l1_bias: Vec<f32>,
-    #[serde(rename = "dense_1/kernel:0")]
-    pub l1_kernel: Matrix,
-
-    #[serde(rename = "dense_2/bias:0")]
-    pub l2_bias: Vec<f32>,
-    #[serde(rename = "dense_2/kernel:0")]
-    pub l2_kernel: Matrix,
-}
-
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FieldsDescribe {
     mean: HashMap<String, f32>,
     std: HashMap<String, f32>,
 }
 
+/// `Weights`'s regular (de)serialization goes through an untagged `dense_N/kernel:0`-style map
+/// so legacy Keras exports still load, but that requires `deserialize_any`, which bincode's
+/// deserializer doesn't implement. Bincode never has to read someone else's legacy export
+/// though — it only round-trips models this crate itself wrote — so for that one format we
+/// sidestep `Weights`'s custom impl entirely and (de)serialize `BincodeModelData`, a plain,
+/// explicitly-ordered shadow of `ModelData` built from these layers, instead.
+#[cfg(feature = "bincode")]
+#[derive(Serialize, Deserialize)]
+struct BincodeDenseLayer {
+    kernel: Matrix,
+    bias: Vec<f32>,
+}
+
+#[cfg(feature = "bincode")]
+#[derive(Serialize, Deserialize)]
+struct BincodeModelData {
+    norm: FieldsDescribe,
+    layers: Vec<BincodeDenseLayer>,
+    fields: Vec<String>,
+    alpha: f32,
+}
+
+#[cfg(feature = "bincode")]
+impl From<&ModelData> for BincodeModelData {
+    fn from(model: &ModelData) -> Self {
+        Self {
+            norm: model.norm.clone(),
+            layers: model
+                .weights
+                .layers
+                .iter()
+                .map(|layer| BincodeDenseLayer {
+                    kernel: layer.kernel.clone(),
+                    bias: layer.bias.clone(),
+                })
+                .collect(),
+            fields: model.fields.clone(),
+            alpha: model.alpha,
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl From<BincodeModelData> for ModelData {
+    fn from(model: BincodeModelData) -> Self {
+        Self {
+            norm: model.norm,
+            weights: Weights::from_layers(
+                model
+                    .layers
+                    .into_iter()
+                    .map(|layer| (layer.kernel, layer.bias))
+                    .collect(),
+            ),
+            fields: model.fields,
+            alpha: model.alpha,
+        }
+    }
+}
+
+impl FieldsDescribe {
+    pub(crate) fn new(mean: HashMap<String, f32>, std: HashMap<String, f32>) -> Self {
+        Self { mean, std }
+    }
+
+    pub(crate) fn mean(&self, field: &str) -> Option<f32> {
+        self.mean.get(field).copied()
+    }
+
+    pub(crate) fn std(&self, field: &str) -> Option<f32> {
+        self.std.get(field).copied()
+    }
+}
+
 impl ModelData {
     pub fn from_reader<R: Read>(reader: R) -> Result<Self, Error> {
+        Self::from_reader_with_format(reader, ModelFormat::Cbor)
+    }
+
+    pub fn from_reader_with_format<R: Read>(reader: R, format: ModelFormat) -> Result<Self, Error> {
         let buffer = BufReader::new(reader);
-        let model: Self = serde_cbor::from_reader(buffer)?;
+        let model: Self = match format {
+            ModelFormat::Cbor => serde_cbor::from_reader(buffer)?,
+            #[cfg(feature = "json")]
+            ModelFormat::Json => serde_json::from_reader(buffer)?,
+            #[cfg(feature = "msgpack")]
+            ModelFormat::MessagePack => rmp_serde::from_read(buffer)?,
+            // Goes through `BincodeModelData`, not `Self`, directly: see the doc comment on
+            // `BincodeDenseLayer`.
+            #[cfg(feature = "bincode")]
+            ModelFormat::Bincode => {
+                bincode::deserialize_from::<_, BincodeModelData>(buffer)?.into()
+            }
+        };
         Ok(model)
     }
 
-    pub fn predict(&self, input: &Matrix) -> Result<f32, Error> {
-        let a1 = input.dot(&self.weights.l0_kernel)?;
-        let a2 = a1.add(&self.weights.l0_bias)?;
-        let a3 = a2.relu(self.alpha);
+    pub fn to_writer<W: Write>(&self, writer: W) -> Result<(), Error> {
+        self.to_writer_with_format(writer, ModelFormat::Cbor)
+    }
 
-        let b1 = a3.dot(&self.weights.l1_kernel)?;
-        let b2 = b1.add(&self.weights.l1_bias)?;
-        let b3 = b2.relu(self.alpha);
+    pub fn to_writer_with_format<W: Write>(&self, writer: W, format: ModelFormat) -> Result<(), Error> {
+        match format {
+            ModelFormat::Cbor => serde_cbor::to_writer(writer, self)?,
+            #[cfg(feature = "json")]
+            ModelFormat::Json => serde_json::to_writer(writer, self)?,
+            #[cfg(feature = "msgpack")]
+            ModelFormat::MessagePack => rmp_serde::encode::write(&mut { writer }, self)?,
+            #[cfg(feature = "bincode")]
+            ModelFormat::Bincode => bincode::serialize_into(writer, &BincodeModelData::from(self))?,
+        }
+        Ok(())
+    }
+
+    pub fn predict(&self, input: &Matrix) -> Result<f32, Error> {
+        let last = self.weights.layers.len() - 1;
+        let mut current = input.clone();
 
-        let c1 = b3.dot(&self.weights.l2_kernel)?;
-        let c2 = c1.add(&self.weights.l2_bias)?;
+        for (idx, layer) in self.weights.layers.iter().enumerate() {
+            current = current.dot(&layer.kernel)?.add(&layer.bias)?;
+            if idx != last {
+                current = current.relu(self.alpha);
+            }
+        }
 
-        Ok(c2[0][0])
+        Ok(current[0][0])
     }
 
     pub fn norm(&self, input: &HashMap<String, f32>) -> Result<Matrix, Error> {
@@ -82,6 +187,29 @@ impl ModelData {
         let input = self.norm(input)?;
         self.predict(&input)
     }
+
+    /// Computes MAE/RMSE of `norm_predict` over a held-out [`crate::dataset::Dataset`].
+    pub fn evaluate(&self, dataset: &crate::dataset::Dataset) -> Result<crate::dataset::Metrics, Error> {
+        if dataset.0.is_empty() {
+            return Err(Error::EmptyDataset);
+        }
+
+        let mut sum_abs = 0.0;
+        let mut sum_sq = 0.0;
+
+        for (features, target) in dataset.0.iter() {
+            let prediction = self.norm_predict(features)?;
+            let error = prediction - target;
+            sum_abs += error.abs();
+            sum_sq += error * error;
+        }
+
+        let n = dataset.0.len() as f32;
+        Ok(crate::dataset::Metrics {
+            mae: sum_abs / n,
+            rmse: (sum_sq / n).sqrt(),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -145,24 +273,25 @@ pub mod tests {
         let model = get_test_model();
         let input = get_test_input();
         assert_eq!((1, 20), input.size());
+        assert_eq!(3, model.weights.layers.len());
 
-        let a1 = input.dot(&model.weights.l0_kernel).unwrap();
+        let a1 = input.dot(&model.weights.layers[0].kernel).unwrap();
         let a1_expected = Matrix::from_array(vec![-8.07738634, 0.32887421, 2.60496564, 0.14431801]);
         assert!(a1.approx_eq(&a1_expected));
-        let a2 = a1.add(&model.weights.l0_bias).unwrap();
+        let a2 = a1.add(&model.weights.layers[0].bias).unwrap();
         let a2_expected =
             Matrix::from_array(vec![-9.79705103, 1.19654123, 2.06540848, -0.23819596]);
         assert!(a2.approx_eq(&a2_expected));
         let a3 = a2.relu(0.01);
 
-        let b1 = a3.dot(&model.weights.l1_kernel).unwrap();
-        let b2 = b1.add(&model.weights.l1_bias).unwrap();
+        let b1 = a3.dot(&model.weights.layers[1].kernel).unwrap();
+        let b2 = b1.add(&model.weights.layers[1].bias).unwrap();
         let b3 = b2.relu(0.01);
         let b3_expected = Matrix::from_array(vec![-0.00769195, 4.21514198, 5.28356369, 5.090146]);
         assert!(b3.approx_eq(&b3_expected));
 
-        let c1 = b3.dot(&model.weights.l2_kernel).unwrap();
-        let c2 = c1.add(&model.weights.l2_bias).unwrap();
+        let c1 = b3.dot(&model.weights.layers[2].kernel).unwrap();
+        let c2 = c1.add(&model.weights.layers[2].bias).unwrap();
 
         assert!(get_test_result().approx_eq(c2[0][0], MARGIN))
     }
@@ -175,4 +304,42 @@ pub mod tests {
         let norm = model.norm(&get_test_pre_norm()).unwrap();
         assert!(norm.approx_eq(&expected), "normalization is wrong");
     }
+
+    fn assert_round_trips_through(format: crate::ModelFormat) {
+        let model = get_test_model();
+        let input = get_test_input();
+        let expected = model.predict(&input).unwrap();
+
+        let mut buffer = vec![];
+        model
+            .to_writer_with_format(&mut buffer, format)
+            .expect("can't serialize model");
+        let reloaded = ModelData::from_reader_with_format(Cursor::new(buffer), format)
+            .expect("can't restore model");
+
+        assert!(expected.approx_eq(reloaded.predict(&input).unwrap(), MARGIN));
+    }
+
+    #[test]
+    fn test_round_trip_cbor() {
+        assert_round_trips_through(crate::ModelFormat::Cbor);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_round_trip_json() {
+        assert_round_trips_through(crate::ModelFormat::Json);
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_round_trip_msgpack() {
+        assert_round_trips_through(crate::ModelFormat::MessagePack);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_round_trip_bincode() {
+        assert_round_trips_through(crate::ModelFormat::Bincode);
+    }
 }
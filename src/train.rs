@@ -0,0 +1,290 @@
+use crate::matrix::Matrix;
+use crate::model_data::FieldsDescribe;
+use crate::weights::Weights;
+use std::collections::HashMap;
+
+/// One dense layer's weights while training, kept as plain floats so gradients are cheap
+/// to accumulate; converted to [`Matrix`]/`Vec<f32>` only once training is done.
+struct Layer {
+    kernel: Vec<Vec<f32>>,
+    bias: Vec<f32>,
+}
+
+/// Trains a leaky-ReLU MLP of any depth from scratch using mini-batch SGD with MSE loss,
+/// so a `Weights` can be produced without going through an external TensorFlow export.
+pub struct Trainer {
+    layers: Vec<Layer>,
+    alpha: f32,
+}
+
+impl Trainer {
+    /// `layer_sizes` lists the width of every layer, including the input and output, e.g.
+    /// `[20, 4, 4, 1]` for the crate's original 3-dense-layer architecture. `Weights` now
+    /// holds an arbitrary number of layers, so any depth is accepted.
+    pub fn new(layer_sizes: Vec<usize>, alpha: f32) -> Self {
+        assert!(
+            layer_sizes.len() >= 2,
+            "need at least an input and an output layer"
+        );
+
+        // Kernels are He-initialized (small, symmetry-breaking values scaled by the layer's
+        // fan-in) so every unit in a layer doesn't compute the same gradient; an all-zero
+        // kernel would make every hidden activation (and its gradient) identically zero
+        // forever. Biases can safely start at zero.
+        let mut rng = Rng::new(0x2545_f491_4f6c_dd1d);
+        let mut layers = Vec::with_capacity(layer_sizes.len() - 1);
+        for window in layer_sizes.windows(2) {
+            let (inputs, outputs) = (window[0], window[1]);
+            let scale = (2.0 / inputs as f32).sqrt();
+            layers.push(Layer {
+                kernel: (0..inputs)
+                    .map(|_| (0..outputs).map(|_| rng.next_signed_unit() * scale).collect())
+                    .collect(),
+                bias: vec![0.0; outputs],
+            });
+        }
+
+        Self { layers, alpha }
+    }
+
+    /// Fits the network on `(fields, inputs) -> targets` samples and returns the resulting
+    /// weights together with the `mean`/`std` computed from `inputs`, ready to populate
+    /// `FieldsDescribe`.
+    pub fn fit(
+        &mut self,
+        fields: &[String],
+        inputs: &[HashMap<String, f32>],
+        targets: &[f32],
+        epochs: usize,
+        lr: f32,
+        batch_size: usize,
+    ) -> (Weights, FieldsDescribe) {
+        let norm = Self::compute_norm(fields, inputs);
+        let normalized: Vec<Vec<f32>> = inputs
+            .iter()
+            .map(|row| Self::normalize_row(fields, row, &norm))
+            .collect();
+
+        for _epoch in 0..epochs {
+            for (batch_inputs, batch_targets) in normalized
+                .chunks(batch_size)
+                .zip(targets.chunks(batch_size))
+            {
+                self.train_batch(batch_inputs, batch_targets, lr);
+            }
+        }
+
+        (self.to_weights(), norm)
+    }
+
+    fn train_batch(&mut self, inputs: &[Vec<f32>], targets: &[f32], lr: f32) {
+        let mut grad_kernels: Vec<Vec<Vec<f32>>> = self
+            .layers
+            .iter()
+            .map(|l| vec![vec![0.0; l.kernel[0].len()]; l.kernel.len()])
+            .collect();
+        let mut grad_biases: Vec<Vec<f32>> =
+            self.layers.iter().map(|l| vec![0.0; l.bias.len()]).collect();
+
+        for (input, &target) in inputs.iter().zip(targets.iter()) {
+            let (zs, activations) = self.forward(input);
+            let prediction = *activations.last().unwrap().last().unwrap();
+
+            // delta for the output layer: dL/dz = 2 * (y_hat - y), output layer has no relu
+            let mut delta = vec![2.0 * (prediction - target)];
+
+            for layer_idx in (0..self.layers.len()).rev() {
+                let a_prev = &activations[layer_idx];
+                let layer = &self.layers[layer_idx];
+
+                for i in 0..a_prev.len() {
+                    for j in 0..delta.len() {
+                        grad_kernels[layer_idx][i][j] += a_prev[i] * delta[j];
+                    }
+                }
+                for j in 0..delta.len() {
+                    grad_biases[layer_idx][j] += delta[j];
+                }
+
+                if layer_idx > 0 {
+                    let z_prev = &zs[layer_idx - 1];
+                    let mut prev_delta = vec![0.0; a_prev.len()];
+                    for i in 0..a_prev.len() {
+                        let mut sum = 0.0;
+                        for j in 0..delta.len() {
+                            sum += layer.kernel[i][j] * delta[j];
+                        }
+                        prev_delta[i] = sum * leaky_relu_derivative(z_prev[i], self.alpha);
+                    }
+                    delta = prev_delta;
+                }
+            }
+        }
+
+        let batch_len = inputs.len().max(1) as f32;
+        for (layer_idx, layer) in self.layers.iter_mut().enumerate() {
+            for i in 0..layer.kernel.len() {
+                for j in 0..layer.kernel[i].len() {
+                    layer.kernel[i][j] -= lr * grad_kernels[layer_idx][i][j] / batch_len;
+                }
+            }
+            for j in 0..layer.bias.len() {
+                layer.bias[j] -= lr * grad_biases[layer_idx][j] / batch_len;
+            }
+        }
+    }
+
+    /// Runs the forward pass, caching each layer's pre-activation `z` and activation `a`
+    /// (the input counts as `activations[0]`).
+    fn forward(&self, input: &[f32]) -> (Vec<Vec<f32>>, Vec<Vec<f32>>) {
+        let mut zs = Vec::with_capacity(self.layers.len());
+        let mut activations = Vec::with_capacity(self.layers.len() + 1);
+        activations.push(input.to_vec());
+
+        for (idx, layer) in self.layers.iter().enumerate() {
+            let prev = activations.last().unwrap();
+            let mut z = layer.bias.clone();
+            for (i, &x) in prev.iter().enumerate() {
+                for j in 0..z.len() {
+                    z[j] += x * layer.kernel[i][j];
+                }
+            }
+
+            let is_output = idx == self.layers.len() - 1;
+            let a = if is_output {
+                z.clone()
+            } else {
+                z.iter().map(|&v| leaky_relu(v, self.alpha)).collect()
+            };
+
+            zs.push(z);
+            activations.push(a);
+        }
+
+        (zs, activations)
+    }
+
+    fn to_weights(&self) -> Weights {
+        Weights::from_layers(
+            self.layers
+                .iter()
+                .map(|l| (Matrix::from_rows(l.kernel.clone()), l.bias.clone()))
+                .collect(),
+        )
+    }
+
+    fn compute_norm(fields: &[String], inputs: &[HashMap<String, f32>]) -> FieldsDescribe {
+        let mut mean = HashMap::new();
+        let mut std = HashMap::new();
+        let n = inputs.len().max(1) as f32;
+
+        for field in fields {
+            let sum: f32 = inputs.iter().map(|row| *row.get(field).unwrap_or(&0.0)).sum();
+            let field_mean = sum / n;
+
+            let variance: f32 = inputs
+                .iter()
+                .map(|row| {
+                    let x = *row.get(field).unwrap_or(&0.0);
+                    (x - field_mean).powi(2)
+                })
+                .sum::<f32>()
+                / n;
+
+            mean.insert(field.clone(), field_mean);
+            std.insert(field.clone(), variance.sqrt());
+        }
+
+        FieldsDescribe::new(mean, std)
+    }
+
+    fn normalize_row(fields: &[String], row: &HashMap<String, f32>, norm: &FieldsDescribe) -> Vec<f32> {
+        fields
+            .iter()
+            .map(|field| {
+                let x = *row.get(field).unwrap_or(&0.0);
+                let mean = norm.mean(field).unwrap_or(0.0);
+                let std = norm.std(field).unwrap_or(1.0);
+                (x - mean) / std
+            })
+            .collect()
+    }
+}
+
+/// A tiny xorshift64* PRNG, used only to break weight-init symmetry. Deterministic (fixed
+/// seed) so training stays reproducible without pulling in a `rand` dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(if seed == 0 { 1 } else { seed })
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Uniform value in `[-1.0, 1.0)`.
+    fn next_signed_unit(&mut self) -> f32 {
+        let bits = (self.next_u64() >> 40) as u32;
+        (bits as f32 / (1u32 << 24) as f32) * 2.0 - 1.0
+    }
+}
+
+fn leaky_relu(z: f32, alpha: f32) -> f32 {
+    if z > 0.0 {
+        z
+    } else {
+        alpha * z
+    }
+}
+
+fn leaky_relu_derivative(z: f32, alpha: f32) -> f32 {
+    if z > 0.0 {
+        1.0
+    } else {
+        alpha
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_converges_on_linear_data() {
+        let fields = vec!["x".to_string()];
+        let inputs: Vec<HashMap<String, f32>> = (0..50)
+            .map(|i| {
+                let mut m = HashMap::new();
+                m.insert("x".to_string(), i as f32);
+                m
+            })
+            .collect();
+        let targets: Vec<f32> = (0..50).map(|i| 2.0 * i as f32 + 1.0).collect();
+
+        let mut trainer = Trainer::new(vec![1, 4, 4, 1], 0.01);
+        let (weights, norm) = trainer.fit(&fields, &inputs, &targets, 500, 0.01, 10);
+
+        let model = crate::ModelData {
+            norm,
+            weights,
+            fields: fields.clone(),
+            alpha: 0.01,
+        };
+
+        let mut total_error = 0.0;
+        for (input, &target) in inputs.iter().zip(targets.iter()) {
+            let prediction = model.norm_predict(input).unwrap();
+            total_error += (prediction - target).abs();
+        }
+        let mae = total_error / inputs.len() as f32;
+
+        assert!(mae < 1.0, "training did not converge, mae = {}", mae);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::matrix::Matrix;
+use serde::de::{self, Deserializer};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// One dense (fully-connected) layer: `y = x . kernel + bias`.
+#[derive(Debug, Clone)]
+pub struct DenseLayer {
+    pub kernel: Matrix,
+    pub bias: Vec<f32>,
+}
+
+/// The network's weights, as an ordered stack of dense layers, so the crate isn't tied to a
+/// fixed depth. `ModelData::predict` loops over `layers`, applying a leaky-ReLU after every
+/// layer except the last.
+#[derive(Debug, Clone)]
+pub struct Weights {
+    pub layers: Vec<DenseLayer>,
+}
+
+impl Weights {
+    /// Builds a `Weights` from the ordered `(kernel, bias)` pairs produced by [`crate::train::Trainer`].
+    pub(crate) fn from_layers(layers: Vec<(Matrix, Vec<f32>)>) -> Self {
+        Self {
+            layers: layers
+                .into_iter()
+                .map(|(kernel, bias)| DenseLayer { kernel, bias })
+                .collect(),
+        }
+    }
+}
+
+/// A weight file's value is either a layer's bias vector or its kernel matrix; which one
+/// depends only on the key it's stored under (see `layer_and_field`).
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawField {
+    Kernel(Matrix),
+    Bias(Vec<f32>),
+}
+
+/// Parses the Keras export key convention: layer 0 is named `dense`, subsequent layers are
+/// `dense_N`, each followed by `/kernel:0` or `/bias:0`.
+fn layer_and_field(key: &str) -> Option<(usize, &'static str)> {
+    let (name, field) = key.split_once('/')?;
+    let index = if name == "dense" {
+        0
+    } else {
+        name.strip_prefix("dense_")?.parse().ok()?
+    };
+
+    match field {
+        "kernel:0" => Some((index, "kernel")),
+        "bias:0" => Some((index, "bias")),
+        _ => None,
+    }
+}
+
+impl<'de> Deserialize<'de> for Weights {
+    /// Reconstructs the ordered `layers` from the flat `dense_N/kernel:0` / `dense_N/bias:0`
+    /// key convention, so existing Keras-exported models still load.
+    ///
+    /// Relies on the source format being self-describing (CBOR/JSON/MessagePack); bincode
+    /// cannot disambiguate the untagged kernel/bias value and never reaches this impl in the
+    /// first place — `ModelData`'s bincode path (de)serializes a plain, explicitly-ordered
+    /// shadow struct instead, since it only ever round-trips models this crate wrote itself.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw: BTreeMap<String, RawField> = BTreeMap::deserialize(deserializer)?;
+
+        let mut kernels: BTreeMap<usize, Matrix> = BTreeMap::new();
+        let mut biases: BTreeMap<usize, Vec<f32>> = BTreeMap::new();
+
+        for (key, value) in raw {
+            let (index, field) = layer_and_field(&key)
+                .ok_or_else(|| de::Error::custom(format!("unrecognized weight key: {}", key)))?;
+
+            match (field, value) {
+                ("kernel", RawField::Kernel(kernel)) => {
+                    kernels.insert(index, kernel);
+                }
+                ("bias", RawField::Bias(bias)) => {
+                    biases.insert(index, bias);
+                }
+                _ => return Err(de::Error::custom(format!("unexpected value type for key: {}", key))),
+            }
+        }
+
+        if kernels.len() != biases.len() || !kernels.keys().eq(biases.keys()) {
+            return Err(de::Error::custom("mismatched kernel/bias layer indices"));
+        }
+
+        let layers = kernels
+            .into_iter()
+            .zip(biases.into_iter())
+            .map(|((_, kernel), (_, bias))| DenseLayer { kernel, bias })
+            .collect();
+
+        Ok(Self { layers })
+    }
+}
+
+impl Serialize for Weights {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.layers.len() * 2))?;
+        for (index, layer) in self.layers.iter().enumerate() {
+            let name = if index == 0 {
+                "dense".to_string()
+            } else {
+                format!("dense_{}", index)
+            };
+            map.serialize_entry(&format!("{}/kernel:0", name), &layer.kernel)?;
+            map.serialize_entry(&format!("{}/bias:0", name), &layer.bias)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap as CborMap;
+
+    fn raw_layer(kernel: Vec<Vec<f32>>, bias: Vec<f32>) -> (serde_cbor::Value, serde_cbor::Value) {
+        (
+            serde_cbor::value::to_value(Matrix::from_rows(kernel)).unwrap(),
+            serde_cbor::value::to_value(bias).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_deserialize_export_with_different_depth() {
+        let mut raw = CborMap::new();
+        let (k0, b0) = raw_layer(vec![vec![1.0, 2.0], vec![3.0, 4.0]], vec![0.1, 0.2]);
+        raw.insert("dense/kernel:0".to_string(), k0);
+        raw.insert("dense/bias:0".to_string(), b0);
+        let (k1, b1) = raw_layer(vec![vec![5.0], vec![6.0]], vec![0.3]);
+        raw.insert("dense_1/kernel:0".to_string(), k1);
+        raw.insert("dense_1/bias:0".to_string(), b1);
+
+        let bytes = serde_cbor::to_vec(&raw).unwrap();
+        let weights: Weights = serde_cbor::from_slice(&bytes).unwrap();
+
+        assert_eq!(2, weights.layers.len());
+        assert_eq!(vec![0.1, 0.2], weights.layers[0].bias);
+        assert_eq!(vec![0.3], weights.layers[1].bias);
+    }
+
+    #[test]
+    fn test_deserialize_rejects_mismatched_layer_indices() {
+        let mut raw = CborMap::new();
+        let (k0, b0) = raw_layer(vec![vec![1.0]], vec![0.1]);
+        raw.insert("dense/kernel:0".to_string(), k0);
+        raw.insert("dense/bias:0".to_string(), b0);
+
+        // layer 1's bias is present, but its kernel is missing; instead there's a stray
+        // layer 3 kernel. Same total count (2 kernels, 2 biases), different index sets.
+        let (k3, _) = raw_layer(vec![vec![2.0]], vec![0.2]);
+        raw.insert("dense_3/kernel:0".to_string(), k3);
+        let (_, b1) = raw_layer(vec![vec![2.0]], vec![0.2]);
+        raw.insert("dense_1/bias:0".to_string(), b1);
+
+        let bytes = serde_cbor::to_vec(&raw).unwrap();
+        let result: Result<Weights, _> = serde_cbor::from_slice(&bytes);
+
+        assert!(
+            result.is_err(),
+            "mismatched kernel/bias indices must not silently zip together"
+        );
+    }
+}